@@ -137,6 +137,9 @@ pub(super) enum SyntheticTag {
     Blocked,
     Unblocked,
     Blocking,
+    Scheduled,
+    Overdue,
+    Annotated,
 }
 
 #[cfg(test)]
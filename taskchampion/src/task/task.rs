@@ -55,6 +55,7 @@ enum Prop {
     Wait,
     End,
     Entry,
+    Scheduled,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -136,6 +137,18 @@ impl Task {
         false
     }
 
+    /// Determine whether this task is overdue -- that is, that it is pending and its due time
+    /// has passed.
+    pub fn is_overdue(&self) -> bool {
+        if self.get_status() != Status::Pending {
+            return false;
+        }
+        if let Some(ts) = self.get_due() {
+            return ts < Utc::now();
+        }
+        false
+    }
+
     /// Determine whether this task is active -- that is, that it has been started
     /// and not stopped.
     pub fn is_active(&self) -> bool {
@@ -164,6 +177,9 @@ impl Task {
             SyntheticTag::Blocked => self.is_blocked(),
             SyntheticTag::Unblocked => !self.is_blocked(),
             SyntheticTag::Blocking => self.is_blocking(),
+            SyntheticTag::Scheduled => self.get_scheduled().is_some(),
+            SyntheticTag::Overdue => self.is_overdue(),
+            SyntheticTag::Annotated => self.get_annotations().next().is_some(),
         }
     }
 
@@ -257,6 +273,12 @@ impl Task {
         self.get_timestamp(Prop::Due.as_ref())
     }
 
+    /// Get the scheduled time for this task: the time at which it is expected to become
+    /// actionable.
+    pub fn get_scheduled(&self) -> Option<Timestamp> {
+        self.get_timestamp(Prop::Scheduled.as_ref())
+    }
+
     /// Get the UUIDs of tasks on which this task depends.
     ///
     /// This includes all dependencies, regardless of their status.  In fact, it may include
@@ -371,8 +393,11 @@ impl Task {
         self.set_timestamp(Prop::Start.as_ref(), Some(Utc::now()), ops)
     }
 
-    /// Stop the task by removing the `start` key
+    /// Stop the task by removing the `start` key, if the task is active.
     pub fn stop(&mut self, ops: &mut Operations) -> Result<()> {
+        if !self.is_active() {
+            return Ok(());
+        }
         self.set_timestamp(Prop::Start.as_ref(), None, ops)
     }
 
@@ -432,6 +457,14 @@ impl Task {
         self.set_timestamp(Prop::Due.as_ref(), due, ops)
     }
 
+    pub fn set_scheduled(
+        &mut self,
+        scheduled: Option<Timestamp>,
+        ops: &mut Operations,
+    ) -> Result<()> {
+        self.set_timestamp(Prop::Scheduled.as_ref(), scheduled, ops)
+    }
+
     /// Set a user-defined attribute (UDA).  This will fail if the key is defined by the data
     /// model.
     pub fn set_uda(
@@ -818,6 +851,105 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_overdue_not_set() {
+        let task = Task::new(TaskData::new(Uuid::new_v4(), TaskMap::new()), dm());
+        assert!(!task.is_overdue());
+        assert!(!task.has_tag(&stag(SyntheticTag::Overdue)));
+    }
+
+    #[test]
+    fn test_overdue_in_past() {
+        let ts = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+        let task = Task::new(
+            TaskData::new(
+                Uuid::new_v4(),
+                vec![(String::from("due"), format!("{}", ts.timestamp()))]
+                    .drain(..)
+                    .collect(),
+            ),
+            dm(),
+        );
+        assert!(task.is_overdue());
+        assert!(task.has_tag(&stag(SyntheticTag::Overdue)));
+    }
+
+    #[test]
+    fn test_overdue_in_future() {
+        let ts = Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+        let task = Task::new(
+            TaskData::new(
+                Uuid::new_v4(),
+                vec![(String::from("due"), format!("{}", ts.timestamp()))]
+                    .drain(..)
+                    .collect(),
+            ),
+            dm(),
+        );
+        assert!(!task.is_overdue());
+        assert!(!task.has_tag(&stag(SyntheticTag::Overdue)));
+    }
+
+    #[test]
+    fn test_overdue_completed() {
+        let ts = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+        let task = Task::new(
+            TaskData::new(
+                Uuid::new_v4(),
+                vec![
+                    (String::from("due"), format!("{}", ts.timestamp())),
+                    (String::from("status"), String::from("completed")),
+                ]
+                .drain(..)
+                .collect(),
+            ),
+            dm(),
+        );
+        assert!(!task.is_overdue());
+        assert!(!task.has_tag(&stag(SyntheticTag::Overdue)));
+    }
+
+    #[test]
+    fn test_scheduled_new_task() {
+        with_mut_task(
+            |_task, _ops| {},
+            |task| {
+                assert_eq!(task.get_scheduled(), None);
+                assert!(!task.has_tag(&stag(SyntheticTag::Scheduled)));
+            },
+        );
+    }
+
+    #[test]
+    fn test_add_scheduled() {
+        let test_time = Utc.with_ymd_and_hms(2033, 1, 1, 0, 0, 0).unwrap();
+        with_mut_task(
+            |task, ops| {
+                task.set_scheduled(Some(test_time), ops).unwrap();
+            },
+            |task| {
+                assert_eq!(task.get_scheduled(), Some(test_time));
+                assert!(task.has_tag(&stag(SyntheticTag::Scheduled)));
+                // scheduled is part of the data model, so it is not a UDA
+                assert_eq!(task.get_legacy_uda("scheduled"), None);
+            },
+        );
+    }
+
+    #[test]
+    fn test_remove_scheduled() {
+        with_mut_task(
+            |task, ops| {
+                task.data.update("scheduled", Some("some-time".into()), ops);
+                assert!(task.data.has("scheduled"));
+                task.set_scheduled(None, ops).unwrap();
+            },
+            |task| {
+                assert!(!task.data.has("scheduled"));
+            },
+        );
+    }
+
     #[test]
     fn test_get_priority_default() {
         let task = Task::new(TaskData::new(Uuid::new_v4(), TaskMap::new()), dm());
@@ -862,6 +994,23 @@ mod test {
                 }
             ]
         );
+        assert!(task.has_tag(&stag(SyntheticTag::Annotated)));
+    }
+
+    #[test]
+    fn test_annotated_tag() {
+        let task = Task::new(
+            TaskData::new(
+                Uuid::new_v4(),
+                vec![(String::from("annotation_abcde"), String::from("invalid"))]
+                    .drain(..)
+                    .collect(),
+            ),
+            dm(),
+        );
+
+        // invalid annotations do not count
+        assert!(!task.has_tag(&stag(SyntheticTag::Annotated)));
     }
 
     #[test]
@@ -1075,6 +1224,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_start_active() {
+        let mut task = Task::new(
+            TaskData::new(
+                Uuid::new_v4(),
+                vec![(String::from("start"), String::from("1234"))]
+                    .drain(..)
+                    .collect(),
+            ),
+            dm(),
+        );
+        let mut ops = Operations::new();
+        task.start(&mut ops).unwrap();
+        assert_eq!(task.get_value("start"), Some("1234"));
+        assert!(ops.is_empty());
+    }
+
+    #[test]
+    fn test_stop_inactive() {
+        let mut task = Task::new(TaskData::new(Uuid::new_v4(), TaskMap::new()), dm());
+        let mut ops = Operations::new();
+        task.stop(&mut ops).unwrap();
+        assert!(!task.is_active());
+        assert!(ops.is_empty());
+    }
+
     #[test]
     fn test_done() {
         with_mut_task(